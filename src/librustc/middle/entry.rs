@@ -45,6 +45,11 @@ struct EntryContext<'a, 'tcx: 'a> {
 
     // The function that imported to root namespace named 'main'.
     imported_main_fn: Option<(NodeId, Span)>,
+
+    // The name a top-level function must have to be treated as the entry
+    // point, as configured by the (feature-gated) crate-level
+    // `#![entry_name = "..."]` attribute. Defaults to "main".
+    entry_name: String,
 }
 
 impl<'a, 'tcx> ItemLikeVisitor<'tcx> for EntryContext<'a, 'tcx> {
@@ -78,6 +83,27 @@ pub fn find_entry_point(session: &Session, ast_map: &ast_map::Map, defined_as_ma
         return
     }
 
+    let entry_name = match ast_map.krate().attrs.iter().find(|attr| attr.check_name("entry_name")) {
+        Some(attr) => {
+            if !session.features.borrow().entry_name {
+                let msg = "specifying the entry point name with `#![entry_name]` is \
+                           experimentally supported";
+                emit_feature_err(&session.parse_sess, "entry_name", attr.span,
+                                  feature_gate::GateIssue::Language, msg);
+            }
+            match attr.value_str() {
+                Some(name) => name.to_string(),
+                None => {
+                    session.span_err(attr.span,
+                        "`entry_name` attribute requires a string value, \
+                         e.g. `#![entry_name = \"run\"]`");
+                    "main".to_string()
+                }
+            }
+        }
+        None => "main".to_string(),
+    };
+
     let mut ctxt = EntryContext {
         session: session,
         map: ast_map,
@@ -87,6 +113,7 @@ pub fn find_entry_point(session: &Session, ast_map: &ast_map::Map, defined_as_ma
         non_main_fns: Vec::new(),
         defined_as_main: defined_as_main,
         imported_main_fn: None,
+        entry_name: entry_name,
     };
 
     ast_map.krate().visit_all_item_likes(&mut ctxt);
@@ -96,7 +123,11 @@ pub fn find_entry_point(session: &Session, ast_map: &ast_map::Map, defined_as_ma
 
 // Beware, this is duplicated in libsyntax/entry.rs, make sure to keep
 // them in sync.
-fn entry_point_type<F: Fn() -> bool>(item: &Item, at_root: bool, defined_as_main: F) -> EntryPointType {
+fn entry_point_type<F: Fn() -> bool>(item: &Item,
+                                      at_root: bool,
+                                      defined_as_main: F,
+                                      entry_name: &str)
+                                      -> EntryPointType {
     match item.node {
         ItemFn(..) => {
             if attr::contains_name(&item.attrs, "start") {
@@ -105,7 +136,7 @@ fn entry_point_type<F: Fn() -> bool>(item: &Item, at_root: bool, defined_as_main
                 EntryPointType::MainAttr
             } else if defined_as_main() {
                 EntryPointType::ImportedMain
-            } else if item.name == "main" {
+            } else if item.name == entry_name {
                 if at_root {
                     // This is a top-level function so can be 'main'
                     EntryPointType::MainNamed
@@ -124,13 +155,14 @@ fn entry_point_type<F: Fn() -> bool>(item: &Item, at_root: bool, defined_as_main
 fn find_item(item: &Item, ctxt: &mut EntryContext, at_root: bool) {
     match entry_point_type(item,
                            at_root,
-                           || ctxt.defined_as_main.contains(&ctxt.map.local_def_id(item.id))) {
+                           || ctxt.defined_as_main.contains(&ctxt.map.local_def_id(item.id)),
+                           &ctxt.entry_name) {
         EntryPointType::MainNamed => {
             if ctxt.main_fn.is_none() {
                 ctxt.main_fn = Some((item.id, item.span));
             } else {
                 span_err!(ctxt.session, item.span, E0136,
-                          "multiple 'main' functions");
+                          "multiple '{}' functions", ctxt.entry_name);
             }
         },
         EntryPointType::OtherMain => {
@@ -172,7 +204,38 @@ fn find_item(item: &Item, ctxt: &mut EntryContext, at_root: bool) {
     }
 }
 
+// Warn when more than one distinct kind of entry point is present, since
+// `configure_main` below silently prefers one by priority and the others
+// would otherwise only surface as a confusing link-time surprise.
+fn check_entry_conflicts(this: &EntryContext) {
+    let main_named_label = format!("a function named '{}'", this.entry_name);
+    let candidates = [
+        ("a `#[start]` function", this.start_fn),
+        ("a `#[main]` function", this.attr_main_fn),
+        (main_named_label.as_str(), this.main_fn),
+        ("an imported 'main'", this.imported_main_fn),
+    ];
+    let present: Vec<_> = candidates.iter()
+        .filter_map(|&(kind, candidate)| candidate.map(|(_, span)| (kind, span)))
+        .collect();
+
+    if present.len() > 1 {
+        let (_, first_span) = present[0];
+        let mut err = this.session.struct_span_warn(first_span,
+                                                      "multiple competing entry points");
+        for &(kind, span) in &present {
+            err.span_label(span, &format!("{} here", kind));
+        }
+        err.note(&format!("only the highest-priority candidate (`#[start]` > `#[main]` > \
+                           `{}` > imported `main`) is used; the rest are ignored",
+                          this.entry_name));
+        err.emit();
+    }
+}
+
 fn configure_main(this: &mut EntryContext) {
+    check_entry_conflicts(this);
+
     if this.start_fn.is_some() {
         *this.session.entry_fn.borrow_mut() = this.start_fn;
         this.session.entry_type.set(Some(config::EntryStart));
@@ -187,15 +250,36 @@ fn configure_main(this: &mut EntryContext) {
         this.session.entry_type.set(Some(config::EntryMain));
     } else {
         // No main function
-        let mut err = this.session.struct_err("main function not found");
+        let mut err = this.session.struct_err(
+            &format!("`{}` function not found", this.entry_name));
         if !this.non_main_fns.is_empty() {
-            // There were some functions named 'main' though. Try to give the user a hint.
-            err.note("the main function must be defined at the crate level \
-                      but you have one or more functions named 'main' that are not \
-                      defined at the crate level. Either move the definition or \
-                      attach the `#[main]` attribute to override this behavior.");
+            // There were some functions named like the entry point though. Try to
+            // give the user a hint.
+            err.note(&format!("the `{name}` function must be defined at the crate \
+                      level but you have one or more functions named '{name}' that \
+                      are not defined at the crate level. Either move the \
+                      definition or attach the `#[main]` attribute to override \
+                      this behavior.", name = this.entry_name));
             for &(_, span) in &this.non_main_fns {
-                err.span_note(span, "here is a function named 'main'");
+                err.span_note(span, &format!("here is a function named '{}'", this.entry_name));
+            }
+            // If there is exactly one candidate, offer a machine-applicable fix
+            // that attaches `#[main]` to it, so `--error-format=json` consumers
+            // (and IDEs) can apply it without the user re-typing anything.
+            if this.non_main_fns.len() == 1 {
+                let span = this.non_main_fns[0].1;
+                // Insert at the start of the candidate's line, not at `span.lo`
+                // (the start of `fn`), and reuse its indentation verbatim, so the
+                // auto-applied fix lines up with the function it's attached to
+                // instead of landing at column 0.
+                let codemap = this.session.codemap();
+                let line_start = codemap.line_begin_pos(span.lo);
+                let indent_span = Span { lo: line_start, hi: span.lo, expn_id: span.expn_id };
+                let indent = codemap.span_to_snippet(indent_span).unwrap_or_default();
+                let insert_point = Span { lo: line_start, hi: line_start, expn_id: span.expn_id };
+                err.span_suggestion(insert_point,
+                                     "attach the `#[main]` attribute to this function instead",
+                                     format!("{}#[main]\n", indent));
             }
             err.emit();
             this.session.abort_if_errors();
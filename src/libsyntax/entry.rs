@@ -0,0 +1,46 @@
+// Copyright 2012 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use ast;
+use attr;
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum EntryPointType {
+    None,
+    MainNamed,
+    OtherMain,
+    Start,
+    MainAttr,
+    ImportedMain,
+}
+
+// Beware, this is duplicated in librustc/middle/entry.rs, make sure to
+// keep them in sync.
+pub fn entry_point_type(item: &ast::Item, at_root: bool, entry_name: &str) -> EntryPointType {
+    match item.node {
+        ast::ItemKind::Fn(..) => {
+            if attr::contains_name(&item.attrs, "start") {
+                EntryPointType::Start
+            } else if attr::contains_name(&item.attrs, "main") {
+                EntryPointType::MainAttr
+            } else if item.ident.name == entry_name {
+                if at_root {
+                    // This is a top-level function so can be 'main'
+                    EntryPointType::MainNamed
+                } else {
+                    EntryPointType::OtherMain
+                }
+            } else {
+                EntryPointType::None
+            }
+        }
+        _ => EntryPointType::None,
+    }
+}
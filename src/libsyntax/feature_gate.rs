@@ -0,0 +1,73 @@
+// Copyright 2012 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+pub use self::GateIssue::*;
+
+use errors::{self, Handler};
+use parse::ParseSess;
+use syntax_pos::Span;
+
+macro_rules! declare_features {
+    ($((active, $feature: ident, $ver: expr, $issue: expr)),+,) => {
+        /// Represents active features that are currently being implemented or
+        /// currently being considered for addition/removal.
+        const ACTIVE_FEATURES:
+                &'static [(&'static str, &'static str, Option<u32>)] =
+            &[$((stringify!($feature), $ver, $issue)),+];
+
+        /// A set of features to be used by later passes.
+        #[derive(Clone, Default)]
+        pub struct Features {
+            $(pub $feature: bool),+
+        }
+    }
+}
+
+// If you change this, please modify src/doc/unstable-book as well. You must
+// export these features in the work-in-progress table in that document.
+declare_features! (
+    // Re-exporting a function named `main` as the crate's entry point.
+    (active, main_reexport, "1.9.0", Some(31628)),
+
+    // Choosing the entry-point function's name via `#![entry_name = "..."]`.
+    (active, entry_name, "1.12.0", Some(32000)),
+);
+
+pub enum GateIssue {
+    Language,
+    Library(Option<u32>)
+}
+
+pub fn emit_feature_err(sess: &ParseSess, feature: &str, span: Span,
+                         issue: GateIssue, explain: &str) {
+    feature_err(sess.span_diagnostic(), feature, span, issue, explain).emit();
+}
+
+pub fn feature_err<'a>(diag: &'a Handler, feature: &str, span: Span,
+                        issue: GateIssue, explain: &str)
+                        -> errors::DiagnosticBuilder<'a> {
+    let issue = match issue {
+        GateIssue::Language => ACTIVE_FEATURES.iter()
+            .find(|&&(name, _, _)| name == feature)
+            .and_then(|&(_, _, issue)| issue),
+        GateIssue::Library(lib_issue) => lib_issue,
+    };
+
+    let mut err = diag.struct_span_err(span, explain);
+    if let Some(n) = issue {
+        err.help(&format!("add #![feature({})] to the crate attributes to enable, \
+                            see tracking issue #{} for more information",
+                           feature, n));
+    } else {
+        err.help(&format!("add #![feature({})] to the crate attributes to enable",
+                           feature));
+    }
+    err
+}